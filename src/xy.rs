@@ -1,5 +1,9 @@
 use direction::Orientation;
 use std::iter;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Rem, RemAssign, Sub,
+    SubAssign,
+};
 
 /// A generic structure with a value for each axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -16,6 +20,16 @@ impl<T> XY<T> {
         XY { x: x, y: y }
     }
 
+    /// Creates a new `XY` by reading exactly two elements from `iter`.
+    ///
+    /// Returns `None` if `iter` yields fewer than two elements.
+    pub fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let x = iter.next()?;
+        let y = iter.next()?;
+        Some(XY::new(x, y))
+    }
+
     /// Returns `f(self.x, self.y)`
     pub fn fold<U, F>(self, f: F) -> U
     where
@@ -101,6 +115,32 @@ impl<T> XY<T> {
     {
         XY::new(f(self.x, other.x), f(self.y, other.y))
     }
+
+    /// Returns a new `XY` of tuples made by zipping `self`, `a` and `b`.
+    pub fn zip3<U, V>(self, a: XY<U>, b: XY<V>) -> XY<(T, U, V)> {
+        XY::new((self.x, a.x, b.x), (self.y, a.y, b.y))
+    }
+
+    /// Returns a new `XY` by calling `f` on `self`, `a` and `b` for each axis.
+    pub fn zip3_map<U, V, W, F>(self, a: XY<U>, b: XY<V>, f: F) -> XY<W>
+    where
+        F: Fn(T, U, V) -> W,
+    {
+        XY::new(f(self.x, a.x, b.x), f(self.y, a.y, b.y))
+    }
+
+    /// Creates a new `XY` by applying `f` to `x` and `y`, bubbling up any error.
+    pub fn try_map<U, E, F>(self, f: F) -> Result<XY<U>, E>
+    where
+        F: Fn(T) -> Result<U, E>,
+    {
+        Ok(XY::new(f(self.x)?, f(self.y)?))
+    }
+
+    /// Returns a new `XY` with `x` and `y` swapped.
+    pub fn swap(self) -> XY<T> {
+        XY::new(self.y, self.x)
+    }
 }
 
 impl<T: Clone> XY<T> {
@@ -176,3 +216,230 @@ impl<T, U> From<(XY<T>, XY<U>)> for XY<(T, U)> {
         t.zip(u)
     }
 }
+
+impl<T> Index<Orientation> for XY<T> {
+    type Output = T;
+
+    fn index(&self, o: Orientation) -> &T {
+        self.get(o)
+    }
+}
+
+impl<T> IndexMut<Orientation> for XY<T> {
+    fn index_mut(&mut self, o: Orientation) -> &mut T {
+        self.get_mut(o)
+    }
+}
+
+impl<T> IntoIterator for XY<T> {
+    type Item = T;
+    type IntoIter = iter::Chain<iter::Once<T>, iter::Once<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::once(self.x).chain(iter::once(self.y))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a XY<T> {
+    type Item = &'a T;
+    type IntoIter = iter::Chain<iter::Once<&'a T>, iter::Once<&'a T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Component-wise and scalar arithmetic operators, e.g. `XY::new(1, 2) + XY::new(3, 4)`
+// and `XY::new(1, 2) * 3`. Each op is defined in terms of the underlying `T`'s own
+// implementation, so overflow/wrapping semantics match `T` exactly.
+macro_rules! xy_op {
+    ($trait:ident, $method:ident) => {
+        impl<T: $trait<Output = T>> $trait for XY<T> {
+            type Output = XY<T>;
+
+            fn $method(self, other: XY<T>) -> XY<T> {
+                self.zip_map(other, $trait::$method)
+            }
+        }
+
+        impl<T: $trait<Output = T> + Copy> $trait<T> for XY<T> {
+            type Output = XY<T>;
+
+            fn $method(self, other: T) -> XY<T> {
+                self.map(|v| v.$method(other))
+            }
+        }
+    };
+}
+
+macro_rules! xy_op_assign {
+    ($trait:ident, $method:ident) => {
+        impl<T: $trait> $trait for XY<T> {
+            fn $method(&mut self, other: XY<T>) {
+                self.x.$method(other.x);
+                self.y.$method(other.y);
+            }
+        }
+
+        impl<T: $trait + Copy> $trait<T> for XY<T> {
+            fn $method(&mut self, other: T) {
+                self.x.$method(other);
+                self.y.$method(other);
+            }
+        }
+    };
+}
+
+xy_op!(Add, add);
+xy_op!(Sub, sub);
+xy_op!(Mul, mul);
+xy_op!(Div, div);
+xy_op!(Rem, rem);
+
+xy_op_assign!(AddAssign, add_assign);
+xy_op_assign!(SubAssign, sub_assign);
+xy_op_assign!(MulAssign, mul_assign);
+xy_op_assign!(DivAssign, div_assign);
+xy_op_assign!(RemAssign, rem_assign);
+
+impl<T: Neg<Output = T>> Neg for XY<T> {
+    type Output = XY<T>;
+
+    fn neg(self) -> XY<T> {
+        self.map(Neg::neg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+    use std::num::Wrapping;
+
+    #[test]
+    fn component_wise_ops() {
+        let a = XY::new(1, 2);
+        let b = XY::new(3, 4);
+
+        assert_eq!(a + b, XY::new(4, 6));
+        assert_eq!(b - a, XY::new(2, 2));
+        assert_eq!(a * b, XY::new(3, 8));
+        assert_eq!(b / a, XY::new(3, 2));
+        assert_eq!(b % a, XY::new(0, 0));
+    }
+
+    #[test]
+    fn scalar_ops() {
+        let a = XY::new(1, 2);
+
+        assert_eq!(a + 3, XY::new(4, 5));
+        assert_eq!(a - 1, XY::new(0, 1));
+        assert_eq!(a * 2, XY::new(2, 4));
+        assert_eq!(a / 2, XY::new(0, 1));
+        assert_eq!(a % 2, XY::new(1, 0));
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut a = XY::new(1, 2);
+        a += XY::new(1, 1);
+        assert_eq!(a, XY::new(2, 3));
+
+        a -= 1;
+        assert_eq!(a, XY::new(1, 2));
+
+        a *= XY::new(2, 2);
+        assert_eq!(a, XY::new(2, 4));
+
+        a /= 2;
+        assert_eq!(a, XY::new(1, 2));
+
+        a %= XY::new(1, 1);
+        assert_eq!(a, XY::new(0, 0));
+    }
+
+    #[test]
+    fn neg() {
+        let a = XY::new(1, -2);
+        assert_eq!(-a, XY::new(-1, 2));
+    }
+
+    #[test]
+    fn wrapping_semantics_match_t() {
+        let a = XY::new(Wrapping(250u8), Wrapping(10u8));
+        let b = XY::new(Wrapping(10u8), Wrapping(250u8));
+
+        assert_eq!(a + b, XY::new(Wrapping(4u8), Wrapping(4u8)));
+    }
+
+    #[test]
+    fn index_by_orientation() {
+        let mut size = XY::new(1, 2);
+
+        assert_eq!(size[Orientation::Horizontal], 1);
+        assert_eq!(size[Orientation::Vertical], 2);
+
+        size[Orientation::Horizontal] = 42;
+        assert_eq!(size, XY::new(42, 2));
+    }
+
+    #[test]
+    fn into_iterator_owned() {
+        let xy = XY::new(1, 2);
+        let collected: Vec<_> = xy.into_iter().collect();
+        assert_eq!(collected, vec![1, 2]);
+    }
+
+    #[test]
+    fn into_iterator_ref() {
+        let xy = XY::new(1, 2);
+        let collected: Vec<_> = (&xy).into_iter().collect();
+        assert_eq!(collected, vec![&1, &2]);
+    }
+
+    #[test]
+    fn from_iter_reads_first_two() {
+        assert_eq!(XY::from_iter(vec![1, 2]), Some(XY::new(1, 2)));
+        assert_eq!(XY::from_iter(vec![1, 2, 3]), Some(XY::new(1, 2)));
+        assert_eq!(XY::from_iter(vec![1]), None);
+        assert_eq!(XY::from_iter(Vec::<i32>::new()), None);
+    }
+
+    #[test]
+    fn zip3_pairs_axes() {
+        let a = XY::new(1, 2);
+        let b = XY::new('a', 'b');
+        let c = XY::new(true, false);
+
+        assert_eq!(a.zip3(b, c), XY::new((1, 'a', true), (2, 'b', false)));
+    }
+
+    #[test]
+    fn zip3_map_combines_axes() {
+        let a = XY::new(1, 2);
+        let b = XY::new(10, 20);
+        let c = XY::new(100, 200);
+
+        assert_eq!(a.zip3_map(b, c, |x, y, z| x + y + z), XY::new(111, 222));
+    }
+
+    #[test]
+    fn try_map_ok() {
+        let a = XY::new(1usize, 2usize);
+        let result = a.try_map(|v| u16::try_from(v).map_err(|_| "too big"));
+        assert_eq!(result, Ok(XY::new(1u16, 2u16)));
+    }
+
+    #[test]
+    fn try_map_bubbles_error() {
+        let a = XY::new(1usize, usize::from(u16::MAX) + 1);
+        let result = a.try_map(|v| u16::try_from(v).map_err(|_| "too big"));
+        assert_eq!(result, Err("too big"));
+    }
+
+    #[test]
+    fn swap_transposes_axes() {
+        let a = XY::new(1, 2);
+        assert_eq!(a.swap(), XY::new(2, 1));
+    }
+}